@@ -0,0 +1,101 @@
+//! Headless CLI for `checker-core`, so schema conformance checks can run in
+//! CI and pre-commit hooks without the desktop app.
+
+use checker_core::Violation;
+use clap::{Parser, ValueEnum};
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FormatArg {
+    Auto,
+    Json,
+    Ndjson,
+    Csv,
+    Yaml,
+}
+
+impl FormatArg {
+    fn as_str(self) -> &'static str {
+        match self {
+            FormatArg::Auto => "auto",
+            FormatArg::Json => "json",
+            FormatArg::Ndjson => "ndjson",
+            FormatArg::Csv => "csv",
+            FormatArg::Yaml => "yaml",
+        }
+    }
+}
+
+/// Checks a JSON/NDJSON/CSV/YAML file against a `CREATE TABLE` schema.
+#[derive(Parser)]
+#[command(name = "checker-cli", version, about)]
+struct Cli {
+    /// Path to the data file to check.
+    #[arg(long)]
+    json: PathBuf,
+
+    /// Path to a `.sql` file containing a single `CREATE TABLE` statement.
+    #[arg(long)]
+    schema: PathBuf,
+
+    /// Input format, or `auto` to sniff it from the file contents.
+    #[arg(long, value_enum, default_value_t = FormatArg::Auto)]
+    format: FormatArg,
+
+    /// Delimiter to use when `--format csv` (or auto-detected as CSV).
+    #[arg(long, default_value = ",")]
+    csv_delimiter: String,
+
+    /// Print violations as a JSON array instead of plain text.
+    #[arg(long)]
+    json_output: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match check(&cli) {
+        Ok(violations) => {
+            report(&violations, cli.json_output);
+            if violations.is_empty() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("checker-cli: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn check(cli: &Cli) -> checker_core::CommandResult<Vec<Violation>> {
+    let content = fs::read_to_string(&cli.json)?;
+    let sql_str = fs::read_to_string(&cli.schema)?;
+
+    let columns = checker_core::parse_create_table_columns(&sql_str)?;
+    let input_format = checker_core::resolve_format(cli.format.as_str(), &content);
+    let csv_delimiter = checker_core::resolve_csv_delimiter(Some(cli.csv_delimiter.clone()));
+    let records = checker_core::parse_records(&content, input_format, csv_delimiter)?;
+
+    Ok(checker_core::validate_records_against_schema(&records, &columns))
+}
+
+fn report(violations: &[Violation], as_json: bool) {
+    if as_json {
+        println!("{}", serde_json::to_string_pretty(violations).unwrap());
+        return;
+    }
+
+    if violations.is_empty() {
+        println!("No violations found.");
+        return;
+    }
+
+    for violation in violations {
+        println!("[{}] {} ({})", violation.code, violation.message, violation.location);
+    }
+    println!("{} violation(s) found.", violations.len());
+}
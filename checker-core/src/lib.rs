@@ -0,0 +1,1065 @@
+//! The checking logic behind Checker, independent of the Tauri GUI.
+//!
+//! This crate owns schema parsing, record normalization, and conformance
+//! checking so the desktop app and the `checker-cli` binary stay
+//! behavior-identical instead of drifting apart.
+
+use rand::{Rng, RngCore, SeedableRng};
+use serde_json::Value;
+use sqlparser::ast::{ColumnOption, DataType, ExactNumberInfo};
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, Read};
+
+#[derive(Clone, serde::Serialize)]
+pub struct OperationResult<T> {
+    pub data: T,
+    pub duration_ms: u128,
+}
+
+/// A single conformance failure, addressable by tooling without re-parsing
+/// the original record.
+///
+/// `code` is a stable, machine-readable identifier (`empty_value`,
+/// `invalid_numeric`, `null_in_not_null`, `length_exceeded`,
+/// `duplicate_primary_key`, ...) that a caller can group or filter on.
+/// `location` is an RFC 6901 JSON Pointer into the checked document, so it
+/// resolves correctly for nested objects and arrays, not just a flat
+/// array-of-objects.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Violation {
+    pub code: String,
+    pub message: String,
+    pub location: String,
+}
+
+/// Our error type, shared by every checking entry point.
+#[derive(Debug, thiserror::Error)]
+pub enum CommandError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("JSON parsing error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("SQL parsing error: {0}")]
+    Sql(String),
+    #[error("{0}")]
+    Generic(String),
+}
+
+// We must implement serde::Serialize on the error enum
+impl serde::Serialize for CommandError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
+
+pub type CommandResult<T> = Result<T, CommandError>;
+
+/// The shape of the raw text handed to a checking command. A `"auto"`
+/// format argument is sniffed into one of these by [`resolve_format`]; the
+/// explicit names are overrides for when sniffing would be ambiguous (e.g. a
+/// semicolon-delimited CSV export).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InputFormat {
+    Json,
+    Ndjson,
+    Csv,
+    Yaml,
+}
+
+/// Resolves a `format` argument (`"auto"`, `"json"`, `"ndjson"`, `"jsonl"`,
+/// `"csv"`, `"yaml"`/`"yml"`, case-insensitive) to a concrete
+/// [`InputFormat`], sniffing `content` when it's `"auto"` or unrecognized.
+pub fn resolve_format(format: &str, content: &str) -> InputFormat {
+    match format.to_lowercase().as_str() {
+        "json" => InputFormat::Json,
+        "ndjson" | "jsonl" => InputFormat::Ndjson,
+        "csv" => InputFormat::Csv,
+        "yaml" | "yml" => InputFormat::Yaml,
+        _ => sniff_format(content),
+    }
+}
+
+/// Sniffs the first non-whitespace byte and overall shape of `content` to
+/// guess its format: `[`/`{` -> JSON, unless there are multiple top-level
+/// `{...}` lines -> NDJSON; a delimited header line with no braces -> CSV;
+/// anything else (e.g. a leading `---`) -> YAML.
+fn sniff_format(content: &str) -> InputFormat {
+    let trimmed = content.trim_start();
+
+    match trimmed.chars().next() {
+        Some('[') => InputFormat::Json,
+        Some('{') => {
+            let top_level_lines = trimmed
+                .lines()
+                .map(str::trim)
+                .filter(|line| line.starts_with('{') && line.ends_with('}'))
+                .take(2)
+                .count();
+            if top_level_lines > 1 {
+                InputFormat::Ndjson
+            } else {
+                InputFormat::Json
+            }
+        }
+        _ if trimmed.starts_with("---") => InputFormat::Yaml,
+        _ => {
+            let first_line = trimmed.lines().next().unwrap_or("");
+            let has_braces = first_line.contains('{') || first_line.contains('[');
+            let has_delimiter = first_line.contains(',')
+                || first_line.contains('\t')
+                || first_line.contains(';');
+            if !has_braces && has_delimiter {
+                InputFormat::Csv
+            } else {
+                InputFormat::Yaml
+            }
+        }
+    }
+}
+
+/// Turns a `csv_delimiter` argument into the single byte the `csv` crate
+/// wants, defaulting to a comma.
+pub fn resolve_csv_delimiter(csv_delimiter: Option<String>) -> u8 {
+    csv_delimiter
+        .and_then(|d| d.bytes().next())
+        .unwrap_or(b',')
+}
+
+/// Normalizes `content` into the `Vec<serde_json::Value>` every checking
+/// command operates on, regardless of its source format. CSV rows are
+/// mapped to objects keyed by the header with all values left as strings,
+/// so the SQL-numeric/length checks still fire meaningfully.
+pub fn parse_records(content: &str, format: InputFormat, csv_delimiter: u8) -> CommandResult<Vec<Value>> {
+    match format {
+        InputFormat::Json => match serde_json::from_str(content)? {
+            Value::Array(records) => Ok(records),
+            other => Ok(vec![other]),
+        },
+        InputFormat::Ndjson => content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Ok(serde_json::from_str(line)?))
+            .collect(),
+        InputFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .delimiter(csv_delimiter)
+                .from_reader(content.as_bytes());
+            let headers = reader
+                .headers()
+                .map_err(|e| CommandError::Generic(e.to_string()))?
+                .clone();
+            reader
+                .records()
+                .map(|record| {
+                    let record = record.map_err(|e| CommandError::Generic(e.to_string()))?;
+                    let fields: serde_json::Map<String, Value> = headers
+                        .iter()
+                        .zip(record.iter())
+                        .map(|(header, value)| (header.to_string(), Value::String(value.to_string())))
+                        .collect();
+                    Ok(Value::Object(fields))
+                })
+                .collect()
+        }
+        InputFormat::Yaml => {
+            let value: Value = serde_yaml::from_str(content)
+                .map_err(|e| CommandError::Generic(e.to_string()))?;
+            match value {
+                Value::Array(records) => Ok(records),
+                other => Ok(vec![other]),
+            }
+        }
+    }
+}
+
+/// Escapes a single JSON Pointer reference token per RFC 6901 (`~` -> `~0`,
+/// `/` -> `~1`).
+fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Walks every object field and array element reachable from `value`,
+/// invoking `visit` with the JSON Pointer to that location, its local key
+/// (the object key, or the array index as a string), and the value itself.
+fn walk_json(value: &Value, pointer: &str, visit: &mut dyn FnMut(&str, &str, &Value)) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_pointer = format!("{}/{}", pointer, escape_pointer_token(key));
+                visit(&child_pointer, key, child);
+                walk_json(child, &child_pointer, visit);
+            }
+        }
+        Value::Array(arr) => {
+            for (i, child) in arr.iter().enumerate() {
+                let index = i.to_string();
+                let child_pointer = format!("{}/{}", pointer, index);
+                visit(&child_pointer, &index, child);
+                walk_json(child, &child_pointer, visit);
+            }
+        }
+        _ => {}
+    }
+}
+
+pub fn find_empty_values(records: &[Value]) -> Vec<Violation> {
+    let v = Value::Array(records.to_vec());
+    let mut violations = Vec::new();
+
+    walk_json(&v, "", &mut |pointer, key, value| {
+        if value.is_null() || (value.is_string() && value.as_str().unwrap().is_empty()) {
+            violations.push(Violation {
+                code: "empty_value".into(),
+                message: format!("\"{}\" is empty or null", key),
+                location: pointer.to_string(),
+            });
+        }
+    });
+
+    violations
+}
+
+pub fn find_invalid_numeric_values(records: &[Value], sql_str: &str) -> CommandResult<Vec<Violation>> {
+    let dialect = GenericDialect {};
+    let ast =
+        Parser::parse_sql(&dialect, sql_str).map_err(|e| CommandError::Sql(e.to_string()))?;
+
+    let mut numeric_columns = HashSet::new();
+    if let Some(sqlparser::ast::Statement::CreateTable(sqlparser::ast::CreateTable {
+        columns,
+        ..
+    })) = ast.get(0)
+    {
+        for col in columns {
+            let data_type_str = col.data_type.to_string().to_lowercase();
+            if data_type_str.contains("int")
+                || data_type_str.contains("numeric")
+                || data_type_str.contains("decimal")
+                || data_type_str.contains("float")
+                || data_type_str.contains("double")
+            {
+                numeric_columns.insert(col.name.value.clone());
+            }
+        }
+    } else {
+        return Err(CommandError::Sql(
+            "Could not parse a CREATE TABLE statement.".into(),
+        ));
+    }
+
+    let mut violations = Vec::new();
+
+    // Only look at each record's own top-level fields, not nested objects or
+    // arrays reachable from it — a nested field that happens to share a name
+    // with a declared numeric column is not the column, just a namesake.
+    for (index, record) in records.iter().enumerate() {
+        let Some(map) = record.as_object() else {
+            continue;
+        };
+        for key in &numeric_columns {
+            let Some(value) = map.get(key) else {
+                continue;
+            };
+            if value.is_number() {
+                continue;
+            }
+            let location = format!("/{}/{}", index, escape_pointer_token(key));
+            if let Some(s) = value.as_str() {
+                if s.parse::<f64>().is_err() {
+                    violations.push(Violation {
+                        code: "invalid_numeric".into(),
+                        message: format!("\"{}\" is not a valid number: {}", key, s),
+                        location,
+                    });
+                }
+            } else {
+                violations.push(Violation {
+                    code: "invalid_numeric".into(),
+                    message: format!("\"{}\" is not a valid number: {}", key, value),
+                    location,
+                });
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Describes everything we need to know about a single declared column in
+/// order to check a record against it.
+pub struct ColumnContract {
+    pub name: String,
+    pub not_null: bool,
+    pub has_default: bool,
+    pub is_unique: bool,
+    pub char_limit: Option<u64>,
+    pub is_numeric: bool,
+}
+
+/// Collects the column names declared unique by a table-level constraint,
+/// e.g. `PRIMARY KEY (id)` or `UNIQUE (email)`, as opposed to a column-level
+/// `id INT PRIMARY KEY`.
+fn unique_columns_from_constraints(
+    constraints: &[sqlparser::ast::TableConstraint],
+) -> HashSet<String> {
+    let mut names = HashSet::new();
+    for constraint in constraints {
+        match constraint {
+            sqlparser::ast::TableConstraint::Unique { columns, .. }
+            | sqlparser::ast::TableConstraint::PrimaryKey { columns, .. } => {
+                names.extend(columns.iter().map(|c| c.value.clone()));
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
+fn column_contracts(
+    columns: &[sqlparser::ast::ColumnDef],
+    constraints: &[sqlparser::ast::TableConstraint],
+) -> Vec<ColumnContract> {
+    let table_unique_columns = unique_columns_from_constraints(constraints);
+
+    columns
+        .iter()
+        .map(|col| {
+            let mut not_null = false;
+            let mut has_default = false;
+            let mut is_unique = table_unique_columns.contains(&col.name.value);
+            for opt in &col.options {
+                match &opt.option {
+                    ColumnOption::NotNull => not_null = true,
+                    ColumnOption::Default(_) => has_default = true,
+                    ColumnOption::Unique { .. } => is_unique = true,
+                    _ => {}
+                }
+            }
+
+            let char_limit = match &col.data_type {
+                DataType::Varchar(Some(len)) | DataType::Char(Some(len)) => Some(len.length),
+                _ => None,
+            };
+
+            let data_type_str = col.data_type.to_string().to_lowercase();
+            let is_numeric = data_type_str.contains("int")
+                || data_type_str.contains("numeric")
+                || data_type_str.contains("decimal")
+                || data_type_str.contains("float")
+                || data_type_str.contains("double");
+
+            ColumnContract {
+                name: col.name.value.clone(),
+                not_null,
+                has_default,
+                is_unique,
+                char_limit,
+                is_numeric,
+            }
+        })
+        .collect()
+}
+
+/// Parses a `CREATE TABLE` statement into the [`ColumnContract`]s the schema
+/// checks walk.
+pub fn parse_create_table_columns(sql_str: &str) -> CommandResult<Vec<ColumnContract>> {
+    let dialect = GenericDialect {};
+    let ast =
+        Parser::parse_sql(&dialect, sql_str).map_err(|e| CommandError::Sql(e.to_string()))?;
+
+    if let Some(sqlparser::ast::Statement::CreateTable(sqlparser::ast::CreateTable {
+        columns,
+        constraints,
+        ..
+    })) = ast.get(0)
+    {
+        Ok(column_contracts(columns, constraints))
+    } else {
+        Err(CommandError::Sql(
+            "Could not parse a CREATE TABLE statement.".into(),
+        ))
+    }
+}
+
+/// Builds the running per-unique-column `HashSet`s `check_record_against_schema`
+/// needs to catch duplicate PRIMARY KEY / UNIQUE values.
+pub fn new_unique_tracker(columns: &[ColumnContract]) -> HashMap<String, HashSet<String>> {
+    columns
+        .iter()
+        .filter(|c| c.is_unique)
+        .map(|c| (c.name.clone(), HashSet::new()))
+        .collect()
+}
+
+/// Checks a single record against `columns`, appending any violations found
+/// to `violations` and updating the running per-column uniqueness sets.
+/// `index` is the record's position in the overall document and is folded
+/// into each violation's JSON Pointer `location`.
+pub fn check_record_against_schema(
+    index: usize,
+    record: &Value,
+    columns: &[ColumnContract],
+    seen_per_unique_column: &mut HashMap<String, HashSet<String>>,
+    violations: &mut Vec<Violation>,
+) {
+    let map = record.as_object();
+
+    for col in columns {
+        let value = map.and_then(|m| m.get(&col.name));
+        let is_missing = value.is_none();
+        let is_null = matches!(value, Some(Value::Null));
+        let location = format!("/{}/{}", index, escape_pointer_token(&col.name));
+
+        if is_missing || is_null {
+            // DEFAULT only fires when the column is omitted, same as a SQL
+            // INSERT — an explicit `null` still violates NOT NULL.
+            let covered_by_default = is_missing && col.has_default;
+            if col.not_null && !covered_by_default {
+                violations.push(Violation {
+                    code: "null_in_not_null".into(),
+                    message: format!("\"{}\" is required but missing or null", col.name),
+                    location,
+                });
+            }
+            continue;
+        }
+        let value = value.unwrap();
+
+        if let Some(limit) = col.char_limit {
+            if let Some(s) = value.as_str() {
+                if (s.chars().count() as u64) > limit {
+                    violations.push(Violation {
+                        code: "length_exceeded".into(),
+                        message: format!(
+                            "\"{}\" exceeds the declared length of {}: {}",
+                            col.name, limit, s
+                        ),
+                        location: location.clone(),
+                    });
+                }
+            }
+        }
+
+        if col.is_numeric && !value.is_number() {
+            if let Some(s) = value.as_str() {
+                if s.parse::<f64>().is_err() {
+                    violations.push(Violation {
+                        code: "invalid_numeric".into(),
+                        message: format!("\"{}\" is not a valid number: {}", col.name, s),
+                        location: location.clone(),
+                    });
+                }
+            } else {
+                violations.push(Violation {
+                    code: "invalid_numeric".into(),
+                    message: format!("\"{}\" is not a valid number: {}", col.name, value),
+                    location: location.clone(),
+                });
+            }
+        }
+
+        if col.is_unique {
+            let seen = seen_per_unique_column.get_mut(&col.name).unwrap();
+            let key = value.to_string();
+            if !seen.insert(key.clone()) {
+                violations.push(Violation {
+                    code: "duplicate_primary_key".into(),
+                    message: format!("\"{}\" duplicates a prior value: {}", col.name, key),
+                    location,
+                });
+            }
+        }
+    }
+}
+
+/// Checks every record in `records` against `columns`, in order.
+pub fn validate_records_against_schema(records: &[Value], columns: &[ColumnContract]) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut seen_per_unique_column = new_unique_tracker(columns);
+
+    for (i, record) in records.iter().enumerate() {
+        check_record_against_schema(i, record, columns, &mut seen_per_unique_column, &mut violations);
+    }
+
+    violations
+}
+
+/// Peeks at the bytes `reader` currently has buffered to determine whether
+/// the document is a top-level JSON array (`[...]`) as opposed to NDJSON (one value per
+/// line), without consuming the underlying stream.
+pub fn sniff_is_json_array(reader: &mut impl BufRead) -> CommandResult<bool> {
+    let buf = reader.fill_buf()?;
+    Ok(buf.iter().find(|b| !b.is_ascii_whitespace()).copied() == Some(b'['))
+}
+
+/// Streams the elements of a top-level JSON array one at a time without
+/// buffering the whole array in memory, invoking `on_value` with each
+/// element and the number of input bytes consumed so far. Elements may be
+/// objects, arrays, or bare scalars (numbers, strings, bools, null).
+pub fn stream_json_array_values(
+    mut reader: impl Read,
+    mut on_value: impl FnMut(Value, u64) -> CommandResult<()>,
+) -> CommandResult<()> {
+    let mut bytes_read: u64 = 0;
+    let mut byte = [0u8; 1];
+
+    loop {
+        if reader.read(&mut byte)? == 0 {
+            return Err(CommandError::Generic(
+                "Unexpected end of input before '['.".into(),
+            ));
+        }
+        bytes_read += 1;
+        if byte[0].is_ascii_whitespace() {
+            continue;
+        }
+        if byte[0] == b'[' {
+            break;
+        }
+        return Err(CommandError::Generic(
+            "Expected a top-level JSON array.".into(),
+        ));
+    }
+
+    let mut current = Vec::new();
+    let mut depth: i32 = 0;
+    let mut in_string = false;
+    let mut escape = false;
+
+    loop {
+        if reader.read(&mut byte)? == 0 {
+            break;
+        }
+        bytes_read += 1;
+        let c = byte[0];
+
+        if depth == 0 && !in_string {
+            if c.is_ascii_whitespace() {
+                continue;
+            }
+            if c == b',' {
+                // A bare scalar element (number, string, bool, null) never
+                // bumps `depth`, so its boundary is a top-level comma/']'
+                // rather than a matched closing bracket — emit it here.
+                if !current.is_empty() {
+                    let value: Value = serde_json::from_slice(&current)?;
+                    current.clear();
+                    on_value(value, bytes_read)?;
+                }
+                continue;
+            }
+            if c == b']' {
+                if !current.is_empty() {
+                    let value: Value = serde_json::from_slice(&current)?;
+                    current.clear();
+                    on_value(value, bytes_read)?;
+                }
+                break;
+            }
+        }
+
+        current.push(c);
+
+        if in_string {
+            if escape {
+                escape = false;
+            } else if c == b'\\' {
+                escape = true;
+            } else if c == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            b'"' => in_string = true,
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    let value: Value = serde_json::from_slice(&current)?;
+                    current.clear();
+                    on_value(value, bytes_read)?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates `reader`'s contents record-by-record against `columns`,
+/// auto-detecting NDJSON vs. a top-level JSON array, and reporting progress
+/// through `on_progress(rows_processed, bytes_read)` every
+/// `progress_every_rows` records. Used by both the Tauri streaming command
+/// and, in principle, any other long-running host.
+pub fn validate_streaming(
+    mut reader: impl BufRead,
+    columns: &[ColumnContract],
+    progress_every_rows: usize,
+    mut on_progress: impl FnMut(usize, u64),
+) -> CommandResult<Vec<Violation>> {
+    let is_array = sniff_is_json_array(&mut reader)?;
+
+    let mut violations = Vec::new();
+    let mut seen_per_unique_column = new_unique_tracker(columns);
+    let mut rows = 0usize;
+
+    if is_array {
+        stream_json_array_values(reader, |record, bytes_read| {
+            check_record_against_schema(
+                rows,
+                &record,
+                columns,
+                &mut seen_per_unique_column,
+                &mut violations,
+            );
+            rows += 1;
+            if rows % progress_every_rows == 0 {
+                on_progress(rows, bytes_read);
+            }
+            Ok(())
+        })?;
+    } else {
+        let mut stream = serde_json::Deserializer::from_reader(reader).into_iter::<Value>();
+        while let Some(record) = stream.next() {
+            let record = record?;
+            check_record_against_schema(
+                rows,
+                &record,
+                columns,
+                &mut seen_per_unique_column,
+                &mut violations,
+            );
+            rows += 1;
+            if rows % progress_every_rows == 0 {
+                on_progress(rows, stream.byte_offset() as u64);
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
+/// What kind of value a declared column needs for sample-data generation.
+/// Distinct from [`ColumnContract`], which only needs to know enough to
+/// validate a value, not enough to synthesize one from scratch.
+#[derive(Clone, Copy, Debug)]
+enum SampleColumnKind {
+    Integer { max: i64 },
+    Decimal { scale: u64 },
+    Text { max_len: u64 },
+    Date,
+    Timestamp,
+    Boolean,
+    Other,
+}
+
+struct SampleColumn {
+    name: String,
+    kind: SampleColumnKind,
+    not_null: bool,
+    is_unique: bool,
+}
+
+fn classify_data_type(data_type: &DataType) -> SampleColumnKind {
+    match data_type {
+        DataType::Varchar(len) | DataType::Char(len) => SampleColumnKind::Text {
+            max_len: len.as_ref().map(|l| l.length).unwrap_or(32),
+        },
+        DataType::Date => SampleColumnKind::Date,
+        DataType::Timestamp(..) | DataType::Datetime(..) => SampleColumnKind::Timestamp,
+        DataType::Boolean => SampleColumnKind::Boolean,
+        DataType::Decimal(info) | DataType::Numeric(info) => SampleColumnKind::Decimal {
+            scale: match info {
+                ExactNumberInfo::PrecisionAndScale(_, scale) => *scale,
+                _ => 2,
+            },
+        },
+        other => {
+            let name = other.to_string().to_lowercase();
+            // Order matters: "tinyint"/"smallint"/"mediumint"/"bigint" all
+            // contain "int", so the specific widths must be checked before
+            // the generic fallback.
+            if name.contains("tinyint") {
+                SampleColumnKind::Integer { max: 127 }
+            } else if name.contains("smallint") {
+                SampleColumnKind::Integer { max: 32_767 }
+            } else if name.contains("mediumint") {
+                SampleColumnKind::Integer { max: 8_388_607 }
+            } else if name.contains("bigint") {
+                SampleColumnKind::Integer { max: i64::MAX }
+            } else if name.contains("int") {
+                SampleColumnKind::Integer { max: 2_147_483_647 }
+            } else if name.contains("float") || name.contains("double") {
+                SampleColumnKind::Decimal { scale: 4 }
+            } else {
+                SampleColumnKind::Other
+            }
+        }
+    }
+}
+
+fn sample_columns(
+    columns: &[sqlparser::ast::ColumnDef],
+    constraints: &[sqlparser::ast::TableConstraint],
+) -> Vec<SampleColumn> {
+    let table_unique_columns = unique_columns_from_constraints(constraints);
+
+    columns
+        .iter()
+        .map(|col| {
+            let mut not_null = false;
+            let mut is_unique = table_unique_columns.contains(&col.name.value);
+            for opt in &col.options {
+                match &opt.option {
+                    ColumnOption::NotNull => not_null = true,
+                    ColumnOption::Unique { .. } => is_unique = true,
+                    _ => {}
+                }
+            }
+            SampleColumn {
+                name: col.name.value.clone(),
+                kind: classify_data_type(&col.data_type),
+                not_null,
+                is_unique,
+            }
+        })
+        .collect()
+}
+
+const SAMPLE_ASCII_ALPHABET: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+fn random_ascii_string(rng: &mut dyn RngCore, len: usize) -> String {
+    (0..len)
+        .map(|_| SAMPLE_ASCII_ALPHABET[rng.gen_range(0..SAMPLE_ASCII_ALPHABET.len())] as char)
+        .collect()
+}
+
+fn random_iso_date(rng: &mut dyn RngCore) -> String {
+    let year = rng.gen_range(1990..=2030);
+    let month = rng.gen_range(1..=12);
+    let day = rng.gen_range(1..=28);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn random_iso_timestamp(rng: &mut dyn RngCore) -> String {
+    let hour = rng.gen_range(0..24);
+    let minute = rng.gen_range(0..60);
+    let second = rng.gen_range(0..60);
+    format!(
+        "{}T{:02}:{:02}:{:02}Z",
+        random_iso_date(rng),
+        hour,
+        minute,
+        second
+    )
+}
+
+/// A date derived from a monotonically increasing counter rather than the
+/// RNG, so unique DATE columns don't collide once the random range is
+/// exhausted.
+fn counter_iso_date(counter: i64) -> String {
+    let year = 1990 + counter / 336;
+    let month = 1 + (counter / 28) % 12;
+    let day = 1 + counter % 28;
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// As [`counter_iso_date`], but for TIMESTAMP columns.
+fn counter_iso_timestamp(counter: i64) -> String {
+    let hour = (counter / 3600) % 24;
+    let minute = (counter / 60) % 60;
+    let second = counter % 60;
+    format!(
+        "{}T{:02}:{:02}:{:02}Z",
+        counter_iso_date(counter),
+        hour,
+        minute,
+        second
+    )
+}
+
+/// Synthesizes one value for `col`, honoring NOT NULL (always present) vs
+/// nullable (occasionally `null`) and keeping PRIMARY KEY / UNIQUE columns
+/// collision-free via a per-column counter in `pk_counters`, whatever the
+/// column's type.
+fn generate_column_value(
+    col: &SampleColumn,
+    rng: &mut dyn RngCore,
+    pk_counters: &mut HashMap<String, i64>,
+) -> Value {
+    if !col.not_null && !col.is_unique && rng.gen_bool(0.1) {
+        return Value::Null;
+    }
+
+    let counter = if col.is_unique {
+        let counter = pk_counters.entry(col.name.clone()).or_insert(0);
+        *counter += 1;
+        Some(*counter)
+    } else {
+        None
+    };
+
+    match col.kind {
+        SampleColumnKind::Integer { max } => match counter {
+            Some(n) => Value::from(n),
+            None => Value::from(rng.gen_range(0..=max)),
+        },
+        SampleColumnKind::Decimal { scale } => {
+            let scale = scale.min(6);
+            let whole = match counter {
+                Some(n) => n as f64,
+                None => rng.gen_range(0..100_000) as f64,
+            };
+            let fraction = rng.gen_range(0..10_u64.pow(scale as u32)) as f64 / 10f64.powi(scale as i32);
+            serde_json::Number::from_f64(whole + fraction)
+                .map(Value::Number)
+                .unwrap_or_else(|| Value::from(whole as i64))
+        }
+        SampleColumnKind::Text { max_len } => {
+            let max_len = (max_len.clamp(1, 64)) as usize;
+            match counter {
+                Some(n) => {
+                    // Reserve room for the "-{n}" suffix so the result still
+                    // respects the column's own declared length limit.
+                    let suffix = format!("-{}", n);
+                    let text_len = max_len.saturating_sub(suffix.chars().count());
+                    let text = if text_len > 0 {
+                        random_ascii_string(rng, rng.gen_range(1..=text_len))
+                    } else {
+                        String::new()
+                    };
+                    let value = format!("{}{}", text, suffix);
+                    let value = if value.chars().count() > max_len {
+                        value.chars().take(max_len).collect()
+                    } else {
+                        value
+                    };
+                    Value::String(value)
+                }
+                None => Value::String(random_ascii_string(rng, rng.gen_range(1..=max_len))),
+            }
+        }
+        SampleColumnKind::Date => match counter {
+            Some(n) => Value::String(counter_iso_date(n)),
+            None => Value::String(random_iso_date(rng)),
+        },
+        SampleColumnKind::Timestamp => match counter {
+            Some(n) => Value::String(counter_iso_timestamp(n)),
+            None => Value::String(random_iso_timestamp(rng)),
+        },
+        SampleColumnKind::Boolean => match counter {
+            Some(n) => Value::Bool(n % 2 == 1),
+            None => Value::Bool(rng.gen_bool(0.5)),
+        },
+        SampleColumnKind::Other => match counter {
+            Some(n) => Value::String(format!("sample-{}", n)),
+            None => Value::String(format!("sample-{}", rng.gen_range(0..1_000_000))),
+        },
+    }
+}
+
+/// Generates `count` JSON records that satisfy the `CREATE TABLE` statement
+/// in `sql_str`, for building fixtures and negative/positive test cases. A
+/// fixed `seed` makes the output deterministic; `None` seeds from entropy.
+pub fn generate_sample_data(sql_str: &str, count: usize, seed: Option<u64>) -> CommandResult<Vec<Value>> {
+    let dialect = GenericDialect {};
+    let ast =
+        Parser::parse_sql(&dialect, sql_str).map_err(|e| CommandError::Sql(e.to_string()))?;
+
+    let columns = if let Some(sqlparser::ast::Statement::CreateTable(sqlparser::ast::CreateTable {
+        columns,
+        constraints,
+        ..
+    })) = ast.get(0)
+    {
+        sample_columns(columns, constraints)
+    } else {
+        return Err(CommandError::Sql(
+            "Could not parse a CREATE TABLE statement.".into(),
+        ));
+    };
+
+    for col in &columns {
+        if col.is_unique && matches!(col.kind, SampleColumnKind::Boolean) && count > 2 {
+            return Err(CommandError::Generic(format!(
+                "\"{}\" is a UNIQUE/PRIMARY KEY BOOLEAN column, which can hold at most 2 \
+                 collision-free values, but {} rows were requested",
+                col.name, count
+            )));
+        }
+        if let SampleColumnKind::Text { max_len } = col.kind {
+            // The longest "-{n}" suffix must fit, or truncation would repeat values.
+            let longest_suffix = format!("-{}", count).len() as u64;
+            if col.is_unique && count > 1 && longest_suffix > max_len.clamp(1, 64) {
+                return Err(CommandError::Generic(format!(
+                    "\"{}\" is a UNIQUE/PRIMARY KEY text column of length {}, too short \
+                     for {} collision-free values",
+                    col.name, max_len, count
+                )));
+            }
+        }
+    }
+
+    let mut rng: Box<dyn RngCore> = match seed {
+        Some(seed) => Box::new(rand::rngs::StdRng::seed_from_u64(seed)),
+        None => Box::new(rand::rngs::StdRng::from_entropy()),
+    };
+
+    let mut pk_counters = HashMap::new();
+    let mut records = Vec::with_capacity(count);
+    for _ in 0..count {
+        let mut record = serde_json::Map::with_capacity(columns.len());
+        for col in &columns {
+            record.insert(
+                col.name.clone(),
+                generate_column_value(col, &mut *rng, &mut pk_counters),
+            );
+        }
+        records.push(Value::Object(record));
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn missing_not_null_column_without_default_is_a_violation() {
+        let columns = parse_create_table_columns("CREATE TABLE t (id INT NOT NULL)").unwrap();
+        let violations = validate_records_against_schema(&[json!({})], &columns);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "null_in_not_null");
+    }
+
+    #[test]
+    fn missing_not_null_column_with_default_is_not_a_violation() {
+        let columns =
+            parse_create_table_columns("CREATE TABLE t (id INT NOT NULL DEFAULT 0)").unwrap();
+        let violations = validate_records_against_schema(&[json!({})], &columns);
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn explicit_null_still_violates_not_null_even_with_default() {
+        let columns =
+            parse_create_table_columns("CREATE TABLE t (id INT NOT NULL DEFAULT 0)").unwrap();
+        let violations = validate_records_against_schema(&[json!({ "id": null })], &columns);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "null_in_not_null");
+    }
+
+    #[test]
+    fn table_level_primary_key_is_honored_for_uniqueness() {
+        let columns =
+            parse_create_table_columns("CREATE TABLE t (id INT NOT NULL, PRIMARY KEY (id))")
+                .unwrap();
+        let violations =
+            validate_records_against_schema(&[json!({ "id": 1 }), json!({ "id": 1 })], &columns);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "duplicate_primary_key");
+    }
+
+    #[test]
+    fn varchar_length_limit_is_enforced() {
+        let columns = parse_create_table_columns("CREATE TABLE t (name VARCHAR(3))").unwrap();
+        let violations = validate_records_against_schema(&[json!({ "name": "abcd" })], &columns);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "length_exceeded");
+    }
+
+    #[test]
+    fn parse_records_handles_json_array() {
+        let records = parse_records(r#"[{"a":1},{"a":2}]"#, InputFormat::Json, b',').unwrap();
+        assert_eq!(records, vec![json!({"a": 1}), json!({"a": 2})]);
+    }
+
+    #[test]
+    fn parse_records_handles_ndjson() {
+        let content = "{\"a\":1}\n{\"a\":2}\n";
+        let records = parse_records(content, InputFormat::Ndjson, b',').unwrap();
+        assert_eq!(records, vec![json!({"a": 1}), json!({"a": 2})]);
+    }
+
+    #[test]
+    fn parse_records_handles_csv() {
+        let content = "a,b\n1,x\n2,y\n";
+        let records = parse_records(content, InputFormat::Csv, b',').unwrap();
+        assert_eq!(
+            records,
+            vec![json!({"a": "1", "b": "x"}), json!({"a": "2", "b": "y"})]
+        );
+    }
+
+    #[test]
+    fn parse_records_handles_yaml() {
+        let content = "- a: 1\n- a: 2\n";
+        let records = parse_records(content, InputFormat::Yaml, b',').unwrap();
+        assert_eq!(records, vec![json!({"a": 1}), json!({"a": 2})]);
+    }
+
+    #[test]
+    fn stream_json_array_values_handles_objects() {
+        let mut seen = Vec::new();
+        stream_json_array_values(r#"[{"a":1},{"a":2}]"#.as_bytes(), |value, _| {
+            seen.push(value);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(seen, vec![json!({"a": 1}), json!({"a": 2})]);
+    }
+
+    #[test]
+    fn stream_json_array_values_handles_bare_scalars() {
+        let mut seen = Vec::new();
+        stream_json_array_values(r#"[1, "two", null, true]"#.as_bytes(), |value, _| {
+            seen.push(value);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(seen, vec![json!(1), json!("two"), json!(null), json!(true)]);
+    }
+
+    #[test]
+    fn stream_json_array_values_handles_comma_inside_string_scalar() {
+        let mut seen = Vec::new();
+        stream_json_array_values(r#"["a,b", "c"]"#.as_bytes(), |value, _| {
+            seen.push(value);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(seen, vec![json!("a,b"), json!("c")]);
+    }
+
+    #[test]
+    fn generated_sample_data_round_trips_through_validation() {
+        let sql = "CREATE TABLE t (\
+            id INT NOT NULL, \
+            code VARCHAR(6) NOT NULL, \
+            tiny_id TINYINT NOT NULL, \
+            active BOOLEAN, \
+            PRIMARY KEY (id), \
+            UNIQUE (code))";
+        let records = generate_sample_data(sql, 20, Some(1)).unwrap();
+        let columns = parse_create_table_columns(sql).unwrap();
+        let violations = validate_records_against_schema(&records, &columns);
+        assert!(violations.is_empty(), "unexpected violations: {:?}", violations);
+    }
+
+    #[test]
+    fn generate_sample_data_rejects_unique_boolean_beyond_two_rows() {
+        let sql = "CREATE TABLE t (flag BOOLEAN UNIQUE)";
+        assert!(generate_sample_data(sql, 3, Some(1)).is_err());
+    }
+}
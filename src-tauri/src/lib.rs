@@ -1,56 +1,10 @@
-use log::{error, info};
-use serde_json::Value;
-use sqlparser::dialect::GenericDialect;
-use sqlparser::parser::Parser;
+use checker_core::{CommandError, CommandResult, OperationResult, Violation};
+use log::info;
 use std::fs;
 use std::time::Instant;
 use tauri_plugin_dialog::{DialogExt, FilePath};
 use tokio::sync::oneshot;
 
-#[derive(Clone, serde::Serialize)]
-struct OperationResult<T> {
-    data: T,
-    duration_ms: u128,
-}
-
-#[derive(Clone, serde::Serialize)]
-struct EmptyValueResult {
-    index: usize,
-    key: String,
-}
-
-#[derive(Clone, serde::Serialize)]
-struct InvalidNumericResult {
-    index: usize,
-    key: String,
-    value: String,
-}
-
-// A custom error type for our commands
-#[derive(Debug, thiserror::Error)]
-enum CommandError {
-    #[error(transparent)]
-    Io(#[from] std::io::Error),
-    #[error("JSON parsing error: {0}")]
-    Json(#[from] serde_json::Error),
-    #[error("SQL parsing error: {0}")]
-    Sql(String),
-    #[error("{0}")]
-    Generic(String),
-}
-
-// We must implement serde::Serialize on the error enum
-impl serde::Serialize for CommandError {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::ser::Serializer,
-    {
-        serializer.serialize_str(self.to_string().as_ref())
-    }
-}
-
-type CommandResult<T> = Result<T, CommandError>;
-
 #[tauri::command]
 async fn open_and_read_json_file(app: tauri::AppHandle) -> CommandResult<String> {
     let start = Instant::now();
@@ -60,7 +14,11 @@ async fn open_and_read_json_file(app: tauri::AppHandle) -> CommandResult<String>
 
     app.dialog()
         .file()
+        .add_filter("Data files", &["json", "ndjson", "jsonl", "csv", "yaml", "yml"])
         .add_filter("JSON", &["json"])
+        .add_filter("NDJSON", &["ndjson", "jsonl"])
+        .add_filter("CSV", &["csv"])
+        .add_filter("YAML", &["yaml", "yml"])
         .pick_file(move |file_path| {
             let _ = tx.send(file_path);
         });
@@ -84,38 +42,41 @@ async fn open_and_read_json_file(app: tauri::AppHandle) -> CommandResult<String>
     }
 }
 
+/// Normalizes the raw text a checking command receives into records, given
+/// the caller's `format` override (or `"auto"` to sniff it).
+fn read_records(
+    json_str: &str,
+    format: Option<String>,
+    csv_delimiter: Option<String>,
+) -> CommandResult<Vec<serde_json::Value>> {
+    let input_format = checker_core::resolve_format(format.as_deref().unwrap_or("auto"), json_str);
+    checker_core::parse_records(
+        json_str,
+        input_format,
+        checker_core::resolve_csv_delimiter(csv_delimiter),
+    )
+}
+
 #[tauri::command]
-fn find_empty_values(json_str: String) -> CommandResult<OperationResult<Vec<EmptyValueResult>>> {
+fn find_empty_values(
+    json_str: String,
+    format: Option<String>,
+    csv_delimiter: Option<String>,
+) -> CommandResult<OperationResult<Vec<Violation>>> {
     let start = Instant::now();
     info!("Starting search for empty values.");
 
-    let v: Value = serde_json::from_str(&json_str)?;
-    let mut empty_results = Vec::new();
-
-    if let Some(arr) = v.as_array() {
-        for (i, obj) in arr.iter().enumerate() {
-            if let Some(map) = obj.as_object() {
-                for (key, value) in map.iter() {
-                    if value.is_null() || (value.is_string() && value.as_str().unwrap().is_empty())
-                    {
-                        empty_results.push(EmptyValueResult {
-                            index: i,
-                            key: key.clone(),
-                        });
-                    }
-                }
-            }
-        }
-    }
+    let records = read_records(&json_str, format, csv_delimiter)?;
+    let violations = checker_core::find_empty_values(&records);
 
     let duration = start.elapsed();
     info!(
         "Found {} empty values in {}ms.",
-        empty_results.len(),
+        violations.len(),
         duration.as_millis()
     );
     Ok(OperationResult {
-        data: empty_results,
+        data: violations,
         duration_ms: duration.as_millis(),
     })
 }
@@ -124,77 +85,123 @@ fn find_empty_values(json_str: String) -> CommandResult<OperationResult<Vec<Empt
 fn find_invalid_numeric_values(
     json_str: String,
     sql_str: String,
-) -> CommandResult<OperationResult<Vec<InvalidNumericResult>>> {
+    format: Option<String>,
+    csv_delimiter: Option<String>,
+) -> CommandResult<OperationResult<Vec<Violation>>> {
     let start = Instant::now();
     info!("Starting search for invalid numeric values.");
 
-    let dialect = GenericDialect {};
-    let ast =
-        Parser::parse_sql(&dialect, &sql_str).map_err(|e| CommandError::Sql(e.to_string()))?;
-
-    let mut numeric_columns = std::collections::HashSet::new();
-    if let Some(sqlparser::ast::Statement::CreateTable(sqlparser::ast::CreateTable {
-        columns,
-        ..
-    })) = ast.get(0)
-    {
-        for col in columns {
-            let data_type_str = col.data_type.to_string().to_lowercase();
-            if data_type_str.contains("int")
-                || data_type_str.contains("numeric")
-                || data_type_str.contains("decimal")
-                || data_type_str.contains("float")
-                || data_type_str.contains("double")
-            {
-                numeric_columns.insert(col.name.value.clone());
-            }
-        }
-    } else {
-        return Err(CommandError::Sql(
-            "Could not parse a CREATE TABLE statement.".into(),
-        ));
-    }
-    info!("Identified numeric columns from SQL: {:?}", numeric_columns);
-
-    let v: Value = serde_json::from_str(&json_str)?;
-    let mut invalid_results = Vec::new();
-
-    if let Some(arr) = v.as_array() {
-        for (i, obj) in arr.iter().enumerate() {
-            if let Some(map) = obj.as_object() {
-                for (key, value) in map.iter() {
-                    if numeric_columns.contains(key) {
-                        if !value.is_number() {
-                            if let Some(s) = value.as_str() {
-                                if s.parse::<f64>().is_err() {
-                                    invalid_results.push(InvalidNumericResult {
-                                        index: i,
-                                        key: key.clone(),
-                                        value: s.to_string(),
-                                    });
-                                }
-                            } else {
-                                invalid_results.push(InvalidNumericResult {
-                                    index: i,
-                                    key: key.clone(),
-                                    value: value.to_string(),
-                                });
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
+    let records = read_records(&json_str, format, csv_delimiter)?;
+    let violations = checker_core::find_invalid_numeric_values(&records, &sql_str)?;
 
     let duration = start.elapsed();
     info!(
         "Found {} invalid numeric values in {}ms.",
-        invalid_results.len(),
+        violations.len(),
+        duration.as_millis()
+    );
+    Ok(OperationResult {
+        data: violations,
+        duration_ms: duration.as_millis(),
+    })
+}
+
+#[tauri::command]
+fn validate_against_schema(
+    json_str: String,
+    sql_str: String,
+    format: Option<String>,
+    csv_delimiter: Option<String>,
+) -> CommandResult<OperationResult<Vec<Violation>>> {
+    let start = Instant::now();
+    info!("Starting full schema validation.");
+
+    let columns = checker_core::parse_create_table_columns(&sql_str)?;
+    let records = read_records(&json_str, format, csv_delimiter)?;
+    let violations = checker_core::validate_records_against_schema(&records, &columns);
+
+    let duration = start.elapsed();
+    info!(
+        "Found {} schema violations in {}ms.",
+        violations.len(),
+        duration.as_millis()
+    );
+    Ok(OperationResult {
+        data: violations,
+        duration_ms: duration.as_millis(),
+    })
+}
+
+/// Progress update emitted to the webview while a large file streams
+/// through [`validate_file_streaming`].
+#[derive(Clone, serde::Serialize)]
+struct StreamProgress {
+    rows_processed: usize,
+    bytes_read: u64,
+}
+
+/// Progress is only worth a UI repaint every so often; emitting on every
+/// record would flood the webview on a file with millions of rows.
+const PROGRESS_EVERY_ROWS: usize = 500;
+const PROGRESS_EVENT: &str = "validation://progress";
+
+#[tauri::command]
+async fn validate_file_streaming(
+    app: tauri::AppHandle,
+    path: String,
+    sql_str: String,
+) -> CommandResult<OperationResult<Vec<Violation>>> {
+    use tauri::Emitter;
+
+    let start = Instant::now();
+    info!("Starting streaming validation of {:?}", &path);
+
+    let columns = checker_core::parse_create_table_columns(&sql_str)?;
+    let file = fs::File::open(&path)?;
+    let reader = std::io::BufReader::new(file);
+
+    let violations = checker_core::validate_streaming(reader, &columns, PROGRESS_EVERY_ROWS, |rows, bytes_read| {
+        let _ = app.emit(
+            PROGRESS_EVENT,
+            StreamProgress {
+                rows_processed: rows,
+                bytes_read,
+            },
+        );
+    })?;
+
+    let duration = start.elapsed();
+    info!(
+        "Streamed validation found {} schema violations in {}ms.",
+        violations.len(),
+        duration.as_millis()
+    );
+    Ok(OperationResult {
+        data: violations,
+        duration_ms: duration.as_millis(),
+    })
+}
+
+#[tauri::command]
+fn generate_sample_data(
+    sql_str: String,
+    count: usize,
+    seed: Option<u64>,
+) -> CommandResult<OperationResult<String>> {
+    let start = Instant::now();
+    info!("Generating {} sample records from schema.", count);
+
+    let records = checker_core::generate_sample_data(&sql_str, count, seed)?;
+    let data = serde_json::to_string(&records)?;
+
+    let duration = start.elapsed();
+    info!(
+        "Generated {} sample records in {}ms.",
+        records.len(),
         duration.as_millis()
     );
     Ok(OperationResult {
-        data: invalid_results,
+        data,
         duration_ms: duration.as_millis(),
     })
 }
@@ -220,7 +227,10 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             open_and_read_json_file,
             find_empty_values,
-            find_invalid_numeric_values
+            find_invalid_numeric_values,
+            validate_against_schema,
+            validate_file_streaming,
+            generate_sample_data
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");